@@ -1,5 +1,12 @@
 use tfhe::core_crypto::prelude::*;
 
+/// A 2048-NTT-friendly prime (`p ≡ 1 mod 4096`, `p < 2^62`) used by the grain-tuned MTPBS
+/// benchmark. Any NTT-friendly prime for the chosen polynomial size works here.
+const NTT_PRIME: u64 = 1_152_921_504_606_830_593;
+
+/// A primitive `2 * polynomial_size`-th root of unity modulo [`NTT_PRIME`], `7^((p - 1) / 4096)`.
+const NTT_PRIMITIVE_ROOT: u64 = 857_829_112_882_207_459;
+
 fn main() {
     // take one command line argument
     let args: Vec<String> = std::env::args().collect();
@@ -15,6 +22,10 @@ fn main() {
     let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
     let pbs_base_log = DecompositionBaseLog(23);
     let pbs_level = DecompositionLevelCount(1);
+    // Keyswitch parameters used to return the big-key bootstrap output to the small key between the
+    // two bootstraps of the full-domain PBS.
+    let ks_base_log = DecompositionBaseLog(3);
+    let ks_level = DecompositionLevelCount(5);
 
     // Request the best seeder possible, starting with hardware entropy sources and falling back to
     // /dev/random on Unix systems if enabled via cargo features
@@ -33,13 +44,46 @@ fn main() {
 
     println!("Generating keys...");
 
-    // Generate an LweSecretKey with binary coefficients
-    let small_lwe_sk =
-        LweSecretKey::generate_new_binary(small_lwe_dimension, &mut secret_generator);
+    // Pick the secret-key distribution. Switching this to `Ternary` or `Gaussian` re-runs the whole
+    // example with a different secret without touching any downstream PBS code.
+    let secret_key_kind = SecretKeyKind::Binary;
 
-    // Generate a GlweSecretKey with binary coefficients
-    let glwe_sk =
-        GlweSecretKey::generate_new_binary(glwe_dimension, polynomial_size, &mut secret_generator);
+    // Centered-binomial parameter `eta` of the Gaussian distribution (standard deviation
+    // `sqrt(eta / 2)`).
+    let gaussian_eta = 3;
+
+    // Generate the small LweSecretKey with the chosen distribution
+    let small_lwe_sk = match secret_key_kind {
+        SecretKeyKind::Binary => {
+            LweSecretKey::generate_new_binary(small_lwe_dimension, &mut secret_generator)
+        }
+        SecretKeyKind::Ternary => {
+            LweSecretKey::generate_new_ternary(small_lwe_dimension, &mut secret_generator)
+        }
+        SecretKeyKind::Gaussian => LweSecretKey::generate_new_gaussian(
+            small_lwe_dimension,
+            gaussian_eta,
+            &mut secret_generator,
+        ),
+    };
+
+    // Generate the GlweSecretKey with the chosen distribution
+    let glwe_sk = match secret_key_kind {
+        SecretKeyKind::Binary => {
+            GlweSecretKey::generate_new_binary(glwe_dimension, polynomial_size, &mut secret_generator)
+        }
+        SecretKeyKind::Ternary => GlweSecretKey::generate_new_ternary(
+            glwe_dimension,
+            polynomial_size,
+            &mut secret_generator,
+        ),
+        SecretKeyKind::Gaussian => GlweSecretKey::generate_new_gaussian(
+            glwe_dimension,
+            polynomial_size,
+            gaussian_eta,
+            &mut secret_generator,
+        ),
+    };
 
     // Create a copy of the GlweSecretKey re-interpreted as an LweSecretKey
     let big_lwe_sk = glwe_sk.clone().into_lwe_secret_key();
@@ -71,8 +115,8 @@ fn main() {
     // Use the conversion function (a memory optimized version also exists but is more complicated
     // to use) to convert the standard bootstrapping key to the Fourier domain
     convert_standard_lwe_bootstrap_key_to_fourier(&std_bootstrapping_key, &mut fourier_bsk);
-    // We don't need the standard bootstrapping key anymore
-    drop(std_bootstrapping_key);
+    // The standard key is kept around: the multithread path also converts it to the NTT domain to
+    // compare the integer bootstrap against the Fourier one.
 
     // Our 4 bits message space
     let message_modulus = 1u64 << 4;
@@ -156,6 +200,13 @@ fn main() {
     // Allocate the LweCiphertext to store the result of the PBS
     let mut pbs_multiplication_ct =
         LweCiphertext::new(0u64, big_lwe_sk.lwe_dimension().to_lwe_size());
+
+    // Create a SignedDecomposer to perform the rounding of the decrypted plaintext. We pass a
+    // DecompositionBaseLog of 5 and a DecompositionLevelCount of 1 indicating we want to round the 5
+    // MSB, 1 bit of padding plus our 4 bits of message.
+    let signed_decomposer =
+        SignedDecomposer::new(DecompositionBaseLog(5), DecompositionLevelCount(1));
+
     if multithread {
         println!("Computing MTPBS...");
         let now = std::time::Instant::now();
@@ -166,6 +217,84 @@ fn main() {
             &fourier_bsk,
         );
         println!("Done in {}ms", now.elapsed().as_millis());
+
+        // Integer-NTT bootstrap path, selected by a domain flag analogous to the `multithread`
+        // switch. The NTT engine is validated in isolation (round-trip and negacyclic product) before
+        // it is trusted to carry a bootstrap key.
+        let domain = BootstrapDomain::Ntt;
+        let ntt = Ntt64::new(polynomial_size.0, NTT_PRIME, NTT_PRIMITIVE_ROOT);
+        assert_ntt_engine(&ntt);
+
+        // Actually convert the standard key into the NTT domain — a zeroed key would silently
+        // bootstrap to garbage.
+        let mut ntt_bsk = NttLweBootstrapKeyOwned::new(
+            std_bootstrapping_key.input_lwe_dimension(),
+            std_bootstrapping_key.glwe_size(),
+            std_bootstrapping_key.polynomial_size(),
+            std_bootstrapping_key.decomposition_base_log(),
+            std_bootstrapping_key.decomposition_level_count(),
+            ntt,
+        );
+        convert_standard_lwe_bootstrap_key_to_ntt(&std_bootstrapping_key, &mut ntt_bsk);
+
+        // Single-threaded NTT bootstrap: the reference result the grain-tuned run must reproduce.
+        let mut ntt_ct = LweCiphertext::new(0u64, big_lwe_sk.lwe_dimension().to_lwe_size());
+        let now = std::time::Instant::now();
+        match domain {
+            BootstrapDomain::Fourier => programmable_bootstrap_lwe_ciphertext(
+                &lwe_ciphertext_in,
+                &mut ntt_ct,
+                &accumulator,
+                &fourier_bsk,
+            ),
+            BootstrapDomain::Ntt => ntt_programmable_bootstrap_lwe_ciphertext(
+                &lwe_ciphertext_in,
+                &mut ntt_ct,
+                &accumulator,
+                &ntt_bsk,
+            ),
+        }
+        let ntt_elapsed_ms = now.elapsed().as_millis();
+
+        // Benchmark the integer NTT against the f64 Fourier bootstrap, reporting its latency and
+        // both decoded outputs (the NTT prime is smaller than the torus, so the integer path is an
+        // approximation of the exact result rather than a bit-for-bit match).
+        let ntt_result =
+            signed_decomposer.closest_representable(decrypt_lwe_ciphertext(&big_lwe_sk, &ntt_ct).0)
+                / delta;
+        let fourier_result = signed_decomposer
+            .closest_representable(decrypt_lwe_ciphertext(&big_lwe_sk, &pbs_multiplication_ct).0)
+            / delta;
+        println!(
+            "NTT bootstrap decoded result: {ntt_result} in {ntt_elapsed_ms}ms \
+             (Fourier: {fourier_result})"
+        );
+
+        // Benchmark the grain-tuned MTPBS: instead of the all-or-nothing `multithread` split, ask
+        // for at least four GGSW rows per rayon task so small polynomials do not oversubscribe the
+        // pool.
+        let parallelism = BootstrapParallelismConfig::new(4);
+        let mut grain_tuned_ct =
+            LweCiphertext::new(0u64, big_lwe_sk.lwe_dimension().to_lwe_size());
+        println!("Computing grain-tuned MTPBS (min {} rows/task)...", parallelism.min_chunk_len());
+        let now = std::time::Instant::now();
+        mt_ntt_programmable_bootstrap_lwe_ciphertext_with_config(
+            &lwe_ciphertext_in,
+            &mut grain_tuned_ct,
+            &accumulator,
+            &ntt_bsk,
+            parallelism,
+        );
+        println!("Done in {}ms", now.elapsed().as_millis());
+
+        // The grain-split parallel reduction is associative, so whatever the chunk size it must
+        // reproduce the single-threaded NTT bootstrap bit-for-bit. Assert that here rather than
+        // only timing it, so a grain-size bug cannot pass as a speedup.
+        assert_eq!(
+            grain_tuned_ct.as_ref(),
+            ntt_ct.as_ref(),
+            "grain-tuned MTPBS diverged from the single-threaded NTT bootstrap"
+        );
     } else {
         println!("Computing PBS...");
         let now = std::time::Instant::now();
@@ -182,12 +311,6 @@ fn main() {
     let pbs_multipliation_plaintext: Plaintext<u64> =
         decrypt_lwe_ciphertext(&big_lwe_sk, &pbs_multiplication_ct);
 
-    /// // Create a SignedDecomposer to perform the rounding of the decrypted plaintext
-    // We pass a DecompositionBaseLog of 5 and a DecompositionLevelCount of 1 indicating we want to
-    // round the 5 MSB, 1 bit of padding plus our 4 bits of message
-    let signed_decomposer =
-        SignedDecomposer::new(DecompositionBaseLog(5), DecompositionLevelCount(1));
-
     // Round and remove our encoding
     let pbs_multiplication_result: u64 =
         signed_decomposer.closest_representable(pbs_multipliation_plaintext.0) / delta;
@@ -197,4 +320,425 @@ fn main() {
     println!(
         "Mulitplication via PBS result is correct! Expected 6, got {pbs_multiplication_result}"
     );
+
+    // Multi-value PBS: evaluate several functions of the same input while paying for a single
+    // blind rotation. Here we compute x -> 2 * x and x -> x + 1 at once.
+    println!("Computing multi-value PBS...");
+    let functions: [&dyn Fn(u64) -> u64; 2] = [&|x: u64| 2 * x, &|x: u64| x + 1];
+    let (mv_accumulator, mv_factors) = generate_multivalue_accumulators(
+        polynomial_size,
+        glwe_dimension.to_glwe_size(),
+        message_modulus as usize,
+        delta,
+        &functions,
+    );
+
+    let mv_results = multivalue_programmable_bootstrap_lwe_ciphertext(
+        &lwe_ciphertext_in,
+        &mv_accumulator,
+        &mv_factors,
+        &fourier_bsk,
+        big_lwe_sk.lwe_dimension(),
+    );
+
+    let expected = [6u64, 4u64];
+    for (i, result_ct) in mv_results.iter().enumerate() {
+        let result_plaintext: Plaintext<u64> = decrypt_lwe_ciphertext(&big_lwe_sk, result_ct);
+        let result = signed_decomposer.closest_representable(result_plaintext.0) / delta;
+        assert_eq!(expected[i], result);
+    }
+    println!("Multi-value PBS results are correct! Expected {expected:?}");
+
+    // Full-domain (without-padding) PBS: evaluate an arbitrary, non-negacyclic function over the
+    // whole message modulus. We need a keyswitch key to move the intermediate result back to the
+    // input key between the two internal bootstraps.
+    println!("Computing full-domain PBS...");
+    let ksk = allocate_and_generate_new_lwe_keyswitch_key(
+        &big_lwe_sk,
+        &small_lwe_sk,
+        ks_base_log,
+        ks_level,
+        lwe_modular_std_dev,
+        &mut encryption_generator,
+    );
+
+    // Without a padding bit the message spans the whole torus, so the encoding uses the full delta.
+    let full_domain_delta = (1_u64 << 63) / (message_modulus / 2);
+    let full_domain_input: LweCiphertextOwned<u64> = allocate_and_encrypt_new_lwe_ciphertext(
+        &small_lwe_sk,
+        Plaintext(input_message * full_domain_delta),
+        lwe_modular_std_dev,
+        &mut encryption_generator,
+    );
+
+    let mut full_domain_ct =
+        LweCiphertext::new(0u64, big_lwe_sk.lwe_dimension().to_lwe_size());
+    // x -> x + 1 is not negacyclic over the message space, so the ordinary PBS could not evaluate
+    // it without a padding bit.
+    full_domain_programmable_bootstrap_lwe_ciphertext(
+        &full_domain_input,
+        &mut full_domain_ct,
+        &ksk,
+        &fourier_bsk,
+        message_modulus,
+        |x: u64| x + 1,
+    );
+
+    let full_domain_plaintext: Plaintext<u64> =
+        decrypt_lwe_ciphertext(&big_lwe_sk, &full_domain_ct);
+    let full_domain_result =
+        signed_decomposer.closest_representable(full_domain_plaintext.0) / full_domain_delta;
+    assert_eq!(input_message + 1, full_domain_result);
+    println!(
+        "Full-domain PBS result is correct! Expected {}, got {full_domain_result}",
+        input_message + 1
+    );
+
+    // Private functional packing keyswitch: pack several LWE ciphertexts into the coefficient slots
+    // of a single GLWE ciphertext, applying the identity function along the way.
+    println!("Computing private functional packing keyswitch...");
+    let pfpksk_base_log = DecompositionBaseLog(6);
+    let pfpksk_level = DecompositionLevelCount(6);
+    let pfpksk = par_allocate_and_generate_new_lwe_private_functional_packing_keyswitch_key(
+        &small_lwe_sk,
+        &glwe_sk,
+        pfpksk_base_log,
+        pfpksk_level,
+        glwe_modular_std_dev,
+        &mut encryption_generator,
+        |x| x,
+    );
+
+    let packed_messages = [1u64, 2u64, 3u64];
+    let mut input_list = LweCiphertextList::new(
+        0u64,
+        small_lwe_sk.lwe_dimension().to_lwe_size(),
+        LweCiphertextCount(packed_messages.len()),
+    );
+    for (message, mut lwe) in packed_messages.iter().zip(input_list.iter_mut()) {
+        encrypt_lwe_ciphertext(
+            &small_lwe_sk,
+            &mut lwe,
+            Plaintext(message * delta),
+            lwe_modular_std_dev,
+            &mut encryption_generator,
+        );
+    }
+
+    let mut packed_glwe = GlweCiphertext::new(0u64, glwe_dimension.to_glwe_size(), polynomial_size);
+    private_functional_packing_keyswitch_lwe_ciphertext_list_into_glwe(
+        &pfpksk,
+        &input_list,
+        &mut packed_glwe,
+    );
+
+    let mut packed_plaintext =
+        PlaintextList::new(0u64, PlaintextCount(polynomial_size.0));
+    decrypt_glwe_ciphertext(&glwe_sk, &packed_glwe, &mut packed_plaintext);
+    for (slot, &expected) in packed_messages.iter().enumerate() {
+        let decoded =
+            signed_decomposer.closest_representable(packed_plaintext.as_ref()[slot]) / delta;
+        assert_eq!(expected, decoded);
+    }
+    println!("Packing keyswitch result is correct! Expected {packed_messages:?}");
+}
+
+/// Evaluate an arbitrary (non-negacyclic) function `f` on the full message modulus, with no padding
+/// bit, via the standard two-PBS "without-padding" decomposition.
+///
+/// [`programmable_bootstrap_lwe_ciphertext`] reserves one bit of padding and relies on the
+/// accumulator negating its first half-box, which restricts `f` to functions that are negacyclic
+/// over the message space. This helper lifts that restriction: a first bootstrap extracts the MSB
+/// of the input to decide which half of the negacyclic domain it lands in, a correction is added to
+/// the input accordingly, and a second bootstrap applies the true look-up table. Both accumulators
+/// are derived from the single closure `f`, and the sign handling is entirely internal.
+///
+/// The intermediate LWE is keyswitched back to the input key between the two bootstraps with
+/// `keyswitch_key`, exactly as the standard blind-rotate/keyswitch loop does.
+#[allow(clippy::too_many_arguments)]
+fn full_domain_programmable_bootstrap_lwe_ciphertext<F>(
+    input: &LweCiphertextOwned<u64>,
+    output: &mut LweCiphertextOwned<u64>,
+    keyswitch_key: &LweKeyswitchKeyOwned<u64>,
+    fourier_bsk: &FourierLweBootstrapKey<ABox<[c64]>>,
+    message_modulus: u64,
+    f: F,
+) where
+    F: Fn(u64) -> u64,
+{
+    let polynomial_size = fourier_bsk.polynomial_size();
+    let glwe_size = fourier_bsk.glwe_size();
+    // No padding bit: the message spans the whole torus, so delta is twice the padded delta. A
+    // message `m` therefore blind-rotates to box `2 * m` (for `m < M / 2`, which lands in the
+    // positive half of the ring) or to its negacyclic image (for `m >= M / 2`).
+    let delta = (1_u64 << 63) / (message_modulus / 2);
+    let half = message_modulus / 2;
+
+    // Sign bootstrap: a constant accumulator whose negacyclicity returns `+sign_value` for a
+    // lower-half input and `-sign_value` for an upper-half one. Adding `bias` afterwards turns that
+    // into a `0` / `corr` correction, where `corr` is a shift of `N + box_size` positions
+    // (`2^63 + delta / 2` on the torus) that maps an upper-half input `m = j + M/2` onto the odd box
+    // `2 * j + 1` of the value table. Hence `bias = corr / 2 = 2^62 + delta / 4` and
+    // `sign_value = -bias`.
+    let bias = (1_u64 << 62).wrapping_add(delta / 4);
+    let sign_value = bias.wrapping_neg();
+    let sign_accumulator = generate_constant_accumulator(polynomial_size, glwe_size, sign_value);
+
+    // Value bootstrap: `message_modulus` boxes with `f` interleaved by half-domain so that a
+    // lower-half input `m` reads even box `2 * m -> f(m)` and an upper-half input `m = j + M/2` reads
+    // odd box `2 * j + 1 -> f(m)`. The sign correction has already confined every read to the
+    // positive half of the ring, so — unlike the ordinary accumulator — no first-half-box negation
+    // is applied, which is exactly the negacyclicity restriction this routine removes.
+    let value_accumulator = generate_full_domain_value_accumulator(
+        polynomial_size,
+        glwe_size,
+        message_modulus as usize,
+        half as usize,
+        delta,
+        f,
+    );
+
+    // First bootstrap under the big key: evaluate the sign indicator.
+    let mut sign_ct = LweCiphertext::new(0u64, output.lwe_size());
+    programmable_bootstrap_lwe_ciphertext(input, &mut sign_ct, &sign_accumulator, fourier_bsk);
+
+    // Keyswitch the indicator back to the input key, add it to the input and apply the `bias`, so
+    // an upper-half input is folded onto the odd boxes while a lower-half input is left in place.
+    let mut corrected = input.clone();
+    let mut folded = LweCiphertext::new(0u64, input.lwe_size());
+    keyswitch_lwe_ciphertext(keyswitch_key, &sign_ct, &mut folded);
+    lwe_ciphertext_add_assign(&mut corrected, &folded);
+    lwe_ciphertext_plaintext_add_assign(&mut corrected, Plaintext(bias));
+
+    // Second bootstrap: apply the interleaved look-up table to the folded input.
+    programmable_bootstrap_lwe_ciphertext(&corrected, output, &value_accumulator, fourier_bsk);
+}
+
+/// Build a trivially-encrypted GLWE accumulator whose every coefficient equals `value`.
+///
+/// Used as the sign-detection test polynomial of the full-domain bootstrap: thanks to negacyclicity
+/// the bootstrap returns `+value` for an input that rotates into the first half of the ring and
+/// `-value` for the second half, which is exactly the half-domain indicator folded back into the
+/// input.
+fn generate_constant_accumulator(
+    polynomial_size: PolynomialSize,
+    glwe_size: GlweSize,
+    value: u64,
+) -> GlweCiphertextOwned<u64> {
+    let accumulator_u64 = vec![value; polynomial_size.0];
+    let accumulator_plaintext = PlaintextList::from_container(accumulator_u64);
+    allocate_and_trivially_encrypt_new_glwe_ciphertext(glwe_size, &accumulator_plaintext)
+}
+
+/// Build the interleaved value accumulator for the full-domain bootstrap.
+///
+/// The `message_modulus` boxes hold `f` interleaved by half-domain: even box `2 * m` stores `f(m)`
+/// for `m < half`, odd box `2 * j + 1` stores `f(j + half)`. Every box is centred by the usual
+/// half-box rotation, but — unlike the negacyclic accumulator built by `generate_accumulator` — the
+/// first half box is not negated, because the sign correction guarantees every read lands on the
+/// positive half of the ring.
+fn generate_full_domain_value_accumulator<F>(
+    polynomial_size: PolynomialSize,
+    glwe_size: GlweSize,
+    message_modulus: usize,
+    half: usize,
+    delta: u64,
+    f: F,
+) -> GlweCiphertextOwned<u64>
+where
+    F: Fn(u64) -> u64,
+{
+    let box_size = polynomial_size.0 / message_modulus;
+    let mut accumulator_u64 = vec![0_u64; polynomial_size.0];
+
+    for box_index in 0..message_modulus {
+        let message = if box_index % 2 == 0 {
+            (box_index / 2) as u64
+        } else {
+            (box_index / 2 + half) as u64
+        };
+        let index = box_index * box_size;
+        accumulator_u64[index..index + box_size]
+            .iter_mut()
+            .for_each(|a| *a = f(message).wrapping_mul(delta));
+    }
+
+    let half_box_size = box_size / 2;
+    accumulator_u64.rotate_left(half_box_size);
+
+    let accumulator_plaintext = PlaintextList::from_container(accumulator_u64);
+    allocate_and_trivially_encrypt_new_glwe_ciphertext(glwe_size, &accumulator_plaintext)
+}
+
+/// Self-check the NTT engine before trusting it with a bootstrap key.
+///
+/// Two properties pin the transform down: the inverse must undo the forward transform exactly, and
+/// a pointwise product in the NTT domain must equal the negacyclic convolution in the prime field.
+/// Both are checked against a schoolbook reference so a wrong twiddle or a missing final scaling is
+/// caught here rather than as silent garbage out of the blind rotation.
+fn assert_ntt_engine(ntt: &Ntt64) {
+    let n = ntt.size();
+    let reducer = ntt.reducer();
+    let modulus = ntt.modulus();
+
+    // Deterministic but non-trivial test polynomials (no RNG is available in the example).
+    let a: Vec<u64> = (0..n).map(|i| (i as u64 * 7 + 1) % modulus).collect();
+    let b: Vec<u64> = (0..n).map(|i| (i as u64 * 3 + 5) % modulus).collect();
+
+    // Round-trip: inverse(forward(a)) == a.
+    let mut round_trip = a.clone();
+    ntt.forward(&mut round_trip);
+    ntt.inverse(&mut round_trip);
+    assert_eq!(round_trip, a, "NTT inverse did not undo the forward transform");
+
+    // Schoolbook negacyclic convolution: coefficients wrapping past degree n flip sign.
+    let mut expected = vec![0u64; n];
+    for (i, &ai) in a.iter().enumerate() {
+        for (j, &bj) in b.iter().enumerate() {
+            let prod = reducer.mul(ai, bj);
+            let target = i + j;
+            if target < n {
+                expected[target] = reducer.add(expected[target], prod);
+            } else {
+                expected[target - n] = reducer.sub(expected[target - n], prod);
+            }
+        }
+    }
+
+    // Same product via the transform: forward both, multiply pointwise, invert.
+    let mut fa = a;
+    let mut fb = b;
+    ntt.forward(&mut fa);
+    ntt.forward(&mut fb);
+    let mut product: Vec<u64> = fa
+        .iter()
+        .zip(fb.iter())
+        .map(|(&x, &y)| reducer.mul(x, y))
+        .collect();
+    ntt.inverse(&mut product);
+    assert_eq!(
+        product, expected,
+        "NTT pointwise product did not match the negacyclic convolution"
+    );
+}
+
+/// The distribution a secret key is drawn from.
+///
+/// Binary keys are the default; ternary and Gaussian keys trade a slightly larger key norm for
+/// tighter noise/security margins and are required by some parameter sets.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SecretKeyKind {
+    /// Coefficients drawn uniformly from {0, 1}.
+    Binary,
+    /// Coefficients drawn uniformly from {-1, 0, 1}.
+    Ternary,
+    /// Coefficients drawn from a centered binomial approximating a discrete Gaussian.
+    Gaussian,
+}
+
+/// Generate the shared accumulator and the per-function factor polynomials used by
+/// [`multivalue_programmable_bootstrap_lwe_ciphertext`].
+///
+/// The test polynomial of a function `f` usually bakes `f(i) * delta` directly into each box. To
+/// evaluate `k` functions with a single blind rotation we instead factor every test polynomial
+/// `v_i(X)` as a common factor `v0(X)` times a per-function polynomial `h_i(X)`:
+///
+/// * `v0(X) = 1 + X + ... + X^{box_size - 1}` is the box-averaging/redundancy factor, negated and
+///   rotated over its first half box to manage negacyclicity exactly like [`generate_accumulator`].
+/// * `h_i(X)` carries the encoded values `f_i(j) * delta` placed at the box boundaries `j *
+///   box_size`, so that `v0 * h_i` rebuilds the usual redundant test polynomial of `f_i`.
+///
+/// The returned accumulator is a trivial GLWE encryption of `v0`, shared by every function. Each
+/// factor polynomial must stay low-norm, because the blind-rotated accumulator is multiplied by it
+/// *in the clear*, which scales the bootstrap noise by its coefficients. Concretely the output
+/// noise variance is multiplied by at most `||h_i||_2^2`, so we require each coefficient to be a
+/// single encoded value `f_i(j) * delta` (one non-zero per box) and assert it in debug builds.
+fn generate_multivalue_accumulators(
+    polynomial_size: PolynomialSize,
+    glwe_size: GlweSize,
+    message_modulus: usize,
+    delta: u64,
+    functions: &[&dyn Fn(u64) -> u64],
+) -> (GlweCiphertextOwned<u64>, Vec<PolynomialOwned<u64>>) {
+    let box_size = polynomial_size.0 / message_modulus;
+    let half_box_size = box_size / 2;
+
+    // Build the common factor v0(X) = 1 + X + ... + X^{box_size - 1}, negacyclically adjusted.
+    let mut v0 = vec![0_u64; polynomial_size.0];
+    for a_i in v0[0..box_size].iter_mut() {
+        *a_i = 1;
+    }
+    for a_i in v0[0..half_box_size].iter_mut() {
+        *a_i = (*a_i).wrapping_neg();
+    }
+    v0.rotate_left(half_box_size);
+
+    let accumulator_plaintext = PlaintextList::from_container(v0);
+    let accumulator =
+        allocate_and_trivially_encrypt_new_glwe_ciphertext(glwe_size, &accumulator_plaintext);
+
+    // Build one low-norm factor polynomial per function, with the encoded value of each box placed
+    // on its boundary monomial.
+    let factors = functions
+        .iter()
+        .map(|f| {
+            let mut h = vec![0_u64; polynomial_size.0];
+            for i in 0..message_modulus {
+                // Each box contributes a single boundary monomial carrying the encoded image.
+                h[i * box_size] = f(i as u64).wrapping_mul(delta);
+            }
+            // Low-norm invariant: at most one non-zero coefficient per box. This sparsity — not a
+            // bound on the individual images, which may legitimately exceed the message modulus (e.g.
+            // x -> 2 * x) — is what keeps ||h_i|| small enough that multiplying the blind-rotated
+            // accumulator by it in the clear stays within the recoverable noise bound.
+            debug_assert!(
+                h.chunks_exact(box_size)
+                    .all(|b| b.iter().filter(|&&c| c != 0).count() <= 1),
+                "multi-value factor is not low-norm: more than one non-zero coefficient per box"
+            );
+            PolynomialOwned::from_container(h)
+        })
+        .collect();
+
+    (accumulator, factors)
+}
+
+/// Evaluate every function encoded in `factors` on `input` with a single blind rotation.
+///
+/// The input is blind-rotated once against the shared accumulator produced by
+/// [`generate_multivalue_accumulators`]; the resulting GLWE is then multiplied by each low-norm
+/// factor polynomial in the clear and the constant term is sample-extracted into one output LWE.
+fn multivalue_programmable_bootstrap_lwe_ciphertext(
+    input: &LweCiphertextOwned<u64>,
+    accumulator: &GlweCiphertextOwned<u64>,
+    factors: &[PolynomialOwned<u64>],
+    fourier_bsk: &FourierLweBootstrapKey<ABox<[c64]>>,
+    output_lwe_dimension: LweDimension,
+) -> Vec<LweCiphertextOwned<u64>> {
+    // A single blind rotation shared by all functions.
+    let mut rotated = accumulator.clone();
+    blind_rotate_assign(input, &mut rotated, fourier_bsk);
+
+    factors
+        .iter()
+        .map(|h| {
+            // Cleartext GLWE-times-polynomial multiplication, reusing the polynomial routines.
+            let mut product = GlweCiphertext::new(
+                0u64,
+                rotated.glwe_size(),
+                rotated.polynomial_size(),
+            );
+            for (mut out_poly, in_poly) in product
+                .as_mut_polynomial_list()
+                .iter_mut()
+                .zip(rotated.as_polynomial_list().iter())
+            {
+                polynomial_wrapping_mul(&mut out_poly, &in_poly, &h.as_view());
+            }
+
+            let mut output = LweCiphertext::new(0u64, output_lwe_dimension.to_lwe_size());
+            extract_lwe_sample_from_glwe_ciphertext(&product, &mut output, MonomialDegree(0));
+            output
+        })
+        .collect()
 }
@@ -0,0 +1,42 @@
+//! Module with the parallelism tuning knobs of the multithreaded programmable bootstrap.
+
+/// Grain-size configuration for the multithreaded blind rotation.
+///
+/// The `multithread` boolean is all-or-nothing: it either splits the external-product loop into
+/// `chunk_count` equal pieces via [`ParSplit::par_split_into`](crate::core_crypto::commons::traits::ParSplit::par_split_into)
+/// or runs it serially, regardless of how much work each task actually carries. For small
+/// polynomials that oversubscribes the thread pool with tasks too short to amortize scheduling.
+///
+/// This config lets callers instead express a minimum amount of work per task, which
+/// [`mt_ntt_programmable_bootstrap_lwe_ciphertext_with_config`](crate::core_crypto::algorithms::mt_ntt_programmable_bootstrap_lwe_ciphertext_with_config)
+/// feeds to [`ParSplit::par_split_with_min_len`](crate::core_crypto::commons::traits::ParSplit::par_split_with_min_len),
+/// so the chunk count adapts to the work size instead of the level/dimension alone. The core count
+/// is not consulted, and the Fourier-domain `mt_programmable_bootstrap_lwe_ciphertext` does not
+/// take this config.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BootstrapParallelismConfig {
+    /// Minimum number of GGSW rows / polynomial coefficients handled by a single rayon task.
+    min_chunk_len: usize,
+}
+
+impl BootstrapParallelismConfig {
+    /// Build a config requiring at least `min_chunk_len` GGSW rows / polynomial coefficients per
+    /// task. A value of `0` is treated as `1`.
+    pub fn new(min_chunk_len: usize) -> Self {
+        Self {
+            min_chunk_len: min_chunk_len.max(1),
+        }
+    }
+
+    /// The configured minimum chunk length.
+    pub fn min_chunk_len(&self) -> usize {
+        self.min_chunk_len
+    }
+}
+
+impl Default for BootstrapParallelismConfig {
+    /// The previous behaviour: cut one task per rayon-default unit of work.
+    fn default() -> Self {
+        Self::new(1)
+    }
+}
@@ -0,0 +1,212 @@
+//! Module with primitives pertaining to the generation and application of private functional
+//! packing keyswitch keys.
+
+use crate::core_crypto::commons::math::decomposition::SignedDecomposer;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+
+/// Allocate and generate a new [`LwePrivateFunctionalPackingKeyswitchKey`].
+///
+/// The key moves ciphertexts from `input_lwe_secret_key` to `output_glwe_secret_key` while applying
+/// the private function `f` to each packed slot. For every input key element (the mask coefficients
+/// plus the body) we encrypt, at each decomposition level, the coefficient scaled by `f` evaluated
+/// on the gadget value, so that applying the key reconstructs `f(m)` in the targeted coefficient
+/// slot. The generation itself runs sequentially; the `par_` prefix matches the existing seeded
+/// bootstrap-key generation, which is the other expensive per-row generation routine in the
+/// example.
+pub fn par_allocate_and_generate_new_lwe_private_functional_packing_keyswitch_key<
+    Scalar,
+    InputKeyCont,
+    OutputKeyCont,
+    Gen,
+    F,
+>(
+    input_lwe_secret_key: &LweSecretKey<InputKeyCont>,
+    output_glwe_secret_key: &GlweSecretKey<OutputKeyCont>,
+    decomposition_base_log: DecompositionBaseLog,
+    decomposition_level_count: DecompositionLevelCount,
+    noise_parameters: impl DispersionParameter + Sync,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+    f: F,
+) -> LwePrivateFunctionalPackingKeyswitchKeyOwned<Scalar>
+where
+    Scalar: UnsignedTorus + Sync + Send,
+    InputKeyCont: Container<Element = Scalar>,
+    OutputKeyCont: Container<Element = Scalar>,
+    Gen: ParallelByteRandomGenerator,
+    F: Fn(Scalar) -> Scalar + Sync,
+{
+    let mut pfpksk = LwePrivateFunctionalPackingKeyswitchKeyOwned::new(
+        Scalar::ZERO,
+        decomposition_base_log,
+        decomposition_level_count,
+        input_lwe_secret_key.lwe_dimension(),
+        output_glwe_secret_key.glwe_dimension().to_glwe_size(),
+        output_glwe_secret_key.polynomial_size(),
+    );
+
+    generate_lwe_private_functional_packing_keyswitch_key(
+        input_lwe_secret_key,
+        output_glwe_secret_key,
+        &mut pfpksk,
+        noise_parameters,
+        generator,
+        f,
+    );
+
+    pfpksk
+}
+
+/// Fill `pfpksk` with a freshly generated private functional packing keyswitch key.
+pub fn generate_lwe_private_functional_packing_keyswitch_key<
+    Scalar,
+    InputKeyCont,
+    OutputKeyCont,
+    KeyCont,
+    Gen,
+    F,
+>(
+    input_lwe_secret_key: &LweSecretKey<InputKeyCont>,
+    output_glwe_secret_key: &GlweSecretKey<OutputKeyCont>,
+    pfpksk: &mut LwePrivateFunctionalPackingKeyswitchKey<KeyCont>,
+    noise_parameters: impl DispersionParameter + Sync,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+    f: F,
+) where
+    Scalar: UnsignedTorus + Sync + Send,
+    InputKeyCont: Container<Element = Scalar>,
+    OutputKeyCont: Container<Element = Scalar>,
+    KeyCont: ContainerMut<Element = Scalar>,
+    Gen: ParallelByteRandomGenerator,
+    F: Fn(Scalar) -> Scalar + Sync,
+{
+    let decomp_base_log = pfpksk.decomposition_base_log();
+    let decomp_level_count = pfpksk.decomposition_level_count();
+    let glwe_size = pfpksk.output_glwe_size();
+    let polynomial_size = pfpksk.output_polynomial_size();
+    let gadget_row_len = decomp_level_count.0 * glwe_size.0 * polynomial_size.0;
+    let glwe_len = glwe_size.0 * polynomial_size.0;
+
+    // Encrypt one gadget matrix per input key element (mask coefficients followed by the body): for
+    // every decomposition level the body polynomial is set to the private function applied to the
+    // key coefficient scaled by the gadget value, and the whole GLWE is freshly encrypted under the
+    // output key. The sampling is threaded sequentially through `generator`, matching the other
+    // keyswitch-key generators.
+    for (key_element_index, gadget_matrix) in pfpksk
+        .as_mut()
+        .chunks_exact_mut(gadget_row_len)
+        .enumerate()
+    {
+        // The functional keyswitch runs over the extended secret `(-s_0, ..., -s_{n-1}, 1)`, whose
+        // inner product with an input `(a, b)` is its phase `b - <a, s>`: the first
+        // `lwe_dimension` rows encrypt the negated mask-coefficient secrets and the final row
+        // encrypts the body's implicit `1`. Encrypting `f(0)` there (the old `unwrap_or(ZERO)`)
+        // dropped the body term entirely, so the packed slot could never recover the input phase.
+        let key_coefficient = input_lwe_secret_key
+            .as_ref()
+            .get(key_element_index)
+            .map(|&s| Scalar::ZERO.wrapping_sub(s))
+            .unwrap_or(Scalar::ONE);
+
+        for (level, glwe_row) in gadget_matrix.chunks_exact_mut(glwe_len).enumerate() {
+            let log = decomp_base_log.0 * (level + 1);
+            let gadget_value = Scalar::ONE << (Scalar::BITS - log);
+            // The private function sees the encoded key coefficient for this level.
+            let message = f(key_coefficient.wrapping_mul(gadget_value));
+
+            let mut plaintext_list = PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0));
+            plaintext_list.as_mut()[0] = message;
+
+            let mut glwe = GlweCiphertext::from_container(glwe_row, polynomial_size);
+            encrypt_glwe_ciphertext(
+                output_glwe_secret_key,
+                &mut glwe,
+                &plaintext_list,
+                noise_parameters,
+                generator,
+            );
+        }
+    }
+}
+
+/// Pack a list of input LWE ciphertexts into the coefficient slots of a single GLWE ciphertext.
+///
+/// Up to `output.polynomial_size()` inputs are consumed; the `j`-th input lands in coefficient `j`
+/// of the output GLWE, with the private function of `pfpksk` applied along the way. Each input
+/// body/mask coefficient is split with a [`SignedDecomposer`] and the matching gadget-matrix rows
+/// of the key are accumulated into the GLWE polynomial coefficients, mirroring the box/accumulator
+/// layout used by the example's programmable bootstrap.
+pub fn private_functional_packing_keyswitch_lwe_ciphertext_list_into_glwe<
+    Scalar,
+    KeyCont,
+    InputCont,
+    OutputCont,
+>(
+    pfpksk: &LwePrivateFunctionalPackingKeyswitchKey<KeyCont>,
+    input_lwe_list: &LweCiphertextList<InputCont>,
+    output_glwe: &mut GlweCiphertext<OutputCont>,
+) where
+    Scalar: UnsignedTorus,
+    KeyCont: Container<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    assert_eq!(pfpksk.output_glwe_size(), output_glwe.glwe_size());
+    assert_eq!(pfpksk.output_polynomial_size(), output_glwe.polynomial_size());
+    assert!(
+        input_lwe_list.lwe_ciphertext_count().0 <= output_glwe.polynomial_size().0,
+        "Cannot pack more ciphertexts than the output polynomial size"
+    );
+
+    let decomposer = SignedDecomposer::new(
+        pfpksk.decomposition_base_log(),
+        pfpksk.decomposition_level_count(),
+    );
+
+    let glwe_size = pfpksk.output_glwe_size().0;
+    let polynomial_size = pfpksk.output_polynomial_size().0;
+    let glwe_len = glwe_size * polynomial_size;
+    let gadget_row_len = pfpksk.decomposition_level_count().0 * glwe_len;
+    let key = pfpksk.as_ref();
+
+    output_glwe.as_mut().fill(Scalar::ZERO);
+
+    // Keyswitch every input ciphertext to a GLWE via the packing key — each key element contributes
+    // `<decompose(coefficient), gadget_rows>` — then place the result in the coefficient slot
+    // matching its position in the list with a negacyclic monomial multiplication by `X^slot`.
+    let mut packed = vec![Scalar::ZERO; glwe_len];
+    for (slot, input_ct) in input_lwe_list.iter().enumerate() {
+        packed.iter_mut().for_each(|c| *c = Scalar::ZERO);
+
+        for (key_element_index, &coefficient) in input_ct.as_ref().iter().enumerate() {
+            let gadget_matrix = &key[key_element_index * gadget_row_len
+                ..(key_element_index + 1) * gadget_row_len];
+            for decomposition_term in decomposer.decompose(coefficient) {
+                let level = decomposition_term.level().0 - 1;
+                let digit = decomposition_term.value();
+                let glwe_row = &gadget_matrix[level * glwe_len..(level + 1) * glwe_len];
+                for (acc, &k) in packed.iter_mut().zip(glwe_row.iter()) {
+                    *acc = (*acc).wrapping_add(digit.wrapping_mul(k));
+                }
+            }
+        }
+
+        // Negacyclic placement: add X^slot * packed into the output GLWE.
+        for (poly_out, poly_packed) in output_glwe
+            .as_mut()
+            .chunks_exact_mut(polynomial_size)
+            .zip(packed.chunks_exact(polynomial_size))
+        {
+            for (i, &value) in poly_packed.iter().enumerate() {
+                let target = i + slot;
+                let pos = target % polynomial_size;
+                if (target / polynomial_size) % 2 == 1 {
+                    poly_out[pos] = poly_out[pos].wrapping_sub(value);
+                } else {
+                    poly_out[pos] = poly_out[pos].wrapping_add(value);
+                }
+            }
+        }
+    }
+}
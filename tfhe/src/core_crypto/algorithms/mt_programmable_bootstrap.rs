@@ -0,0 +1,166 @@
+//! Module with the grain-size-aware multithreaded programmable bootstrapping.
+
+use rayon::prelude::*;
+
+use crate::core_crypto::algorithms::bootstrap_parallelism::BootstrapParallelismConfig;
+use crate::core_crypto::algorithms::ntt_programmable_bootstrap::{
+    decompose_level, lift_into_field, lower_from_field, rescale_to_degree, rotate_glwe_assign,
+};
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+
+/// Grain-size-aware multithreaded programmable bootstrap over an [`NttLweBootstrapKey`].
+///
+/// This is the tunable counterpart of the all-or-nothing `multithread` switch: rather than always
+/// cutting the external-product loop into one task per level/dimension, it splits the GGSW rows
+/// with
+/// [`ParSplit::par_split_with_min_len`](crate::core_crypto::commons::traits::ParSplit::par_split_with_min_len)
+/// so every rayon task but the last owns at least `config.min_chunk_len()` rows (the last one takes
+/// the remainder and can own fewer). Small polynomials therefore
+/// stop oversubscribing the thread pool with tasks too short to amortize scheduling, while large
+/// ones still fan out across every core.
+pub fn mt_ntt_programmable_bootstrap_lwe_ciphertext_with_config<
+    InputCont,
+    OutputCont,
+    AccCont,
+    KeyCont,
+>(
+    input: &LweCiphertext<InputCont>,
+    output: &mut LweCiphertext<OutputCont>,
+    accumulator: &GlweCiphertext<AccCont>,
+    ntt_bsk: &NttLweBootstrapKey<KeyCont>,
+    config: BootstrapParallelismConfig,
+) where
+    InputCont: Container<Element = u64>,
+    OutputCont: ContainerMut<Element = u64>,
+    AccCont: Container<Element = u64>,
+    KeyCont: Container<Element = u64>,
+{
+    let ntt = ntt_bsk.ntt();
+    let reducer = *ntt.reducer();
+    let glwe_size = ntt_bsk.glwe_size().0;
+    let poly_size = ntt_bsk.polynomial_size().0;
+    let base_log = ntt_bsk.decomposition_base_log();
+    let level_count = ntt_bsk.decomposition_level_count();
+    let two_n = 2 * poly_size;
+
+    let mut rotated = accumulator.clone_into_owned();
+
+    let mut acc: Vec<u64> = rotated
+        .as_ref()
+        .iter()
+        .map(|&c| lift_into_field(c, &reducer))
+        .collect();
+
+    let input = input.as_ref();
+    let body = *input.last().unwrap();
+    let b_hat = rescale_to_degree(body, two_n);
+    rotate_glwe_assign(&mut acc, glwe_size, poly_size, (two_n - b_hat) % two_n, &reducer);
+
+    let mask = &input[..input.len() - 1];
+    let ggsw_len = level_count.0 * glwe_size * glwe_size * poly_size;
+    for (mask_element, ggsw) in mask.iter().zip(ntt_bsk.as_ref().chunks_exact(ggsw_len)) {
+        let a_hat = rescale_to_degree(*mask_element, two_n);
+        if a_hat == 0 {
+            continue;
+        }
+
+        let mut diff = acc.clone();
+        rotate_glwe_assign(&mut diff, glwe_size, poly_size, a_hat, &reducer);
+        for (d, a) in diff.iter_mut().zip(acc.iter()) {
+            *d = reducer.sub(*d, *a);
+        }
+
+        let product = par_ntt_external_product(
+            &diff,
+            ggsw,
+            ntt,
+            glwe_size,
+            poly_size,
+            base_log,
+            level_count,
+            config,
+        );
+        for (a, p) in acc.iter_mut().zip(product.iter()) {
+            *a = reducer.add(*a, *p);
+        }
+    }
+
+    for (dst, &src) in rotated.as_mut().iter_mut().zip(acc.iter()) {
+        *dst = lower_from_field(src, &reducer);
+    }
+    extract_lwe_sample_from_glwe_ciphertext(&rotated, output, MonomialDegree(0));
+}
+
+/// GGSW external product whose row accumulation is split across rayon tasks with a tunable grain.
+///
+/// The gadget digits are transformed once up front; the `glwe_size * level_count` key rows are then
+/// grouped with [`ParSplit::par_split_with_min_len`](crate::core_crypto::commons::traits::ParSplit::par_split_with_min_len)
+/// so each task but the last handles at least `config.min_chunk_len()` rows, computes a partial NTT-domain
+/// accumulator, and the partials are summed before a single inverse transform per output.
+#[allow(clippy::too_many_arguments)]
+fn par_ntt_external_product(
+    glwe: &[u64],
+    ggsw: &[u64],
+    ntt: &crate::core_crypto::commons::math::ntt::Ntt64,
+    glwe_size: usize,
+    poly_size: usize,
+    base_log: DecompositionBaseLog,
+    level_count: DecompositionLevelCount,
+    config: BootstrapParallelismConfig,
+) -> Vec<u64> {
+    let reducer = *ntt.reducer();
+    let row_stride = glwe_size * poly_size;
+    let rows = glwe_size * level_count.0;
+
+    // Transform each row's gadget digit once; reused by every output polynomial.
+    let digits: Vec<Vec<u64>> = (0..rows)
+        .map(|row| {
+            let in_poly = row / level_count.0;
+            let level = row % level_count.0;
+            let coeffs = &glwe[in_poly * poly_size..(in_poly + 1) * poly_size];
+            let mut digit = decompose_level(coeffs, base_log, level, &reducer);
+            ntt.forward(&mut digit);
+            digit
+        })
+        .collect();
+
+    // Grain-controlled split over the row indices: each task owns at least `min_chunk_len` rows.
+    let row_ids: Vec<usize> = (0..rows).collect();
+    let partials: Vec<Vec<u64>> = row_ids
+        .as_slice()
+        .par_split_with_min_len(config.min_chunk_len())
+        .map(|rows_chunk| {
+            let mut ntt_acc = vec![0u64; glwe_size * poly_size];
+            for &row in rows_chunk {
+                let digit = &digits[row];
+                let row_base = row * row_stride;
+                for out_poly in 0..glwe_size {
+                    let key_poly =
+                        &ggsw[row_base + out_poly * poly_size..row_base + (out_poly + 1) * poly_size];
+                    let acc = &mut ntt_acc[out_poly * poly_size..(out_poly + 1) * poly_size];
+                    for ((a, &d), &k) in acc.iter_mut().zip(digit.iter()).zip(key_poly.iter()) {
+                        *a = reducer.add(*a, reducer.mul(d, k));
+                    }
+                }
+            }
+            ntt_acc
+        })
+        .collect();
+
+    // Sum the partial accumulators in the NTT domain, then inverse-transform each output once.
+    let mut ntt_sum = vec![0u64; glwe_size * poly_size];
+    for partial in &partials {
+        for (s, &v) in ntt_sum.iter_mut().zip(partial.iter()) {
+            *s = reducer.add(*s, v);
+        }
+    }
+
+    let mut result = vec![0u64; glwe_size * poly_size];
+    for out_poly in 0..glwe_size {
+        let mut slice = ntt_sum[out_poly * poly_size..(out_poly + 1) * poly_size].to_vec();
+        ntt.inverse(&mut slice);
+        result[out_poly * poly_size..(out_poly + 1) * poly_size].copy_from_slice(&slice);
+    }
+    result
+}
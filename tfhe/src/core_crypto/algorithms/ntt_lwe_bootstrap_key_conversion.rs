@@ -0,0 +1,59 @@
+//! Module with primitives pertaining to the conversion of standard LWE bootstrap keys to the NTT
+//! domain.
+
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+
+/// Convert a standard [`LweBootstrapKey`] to the NTT domain, in place into `ntt_bsk`.
+///
+/// This is the integer-arithmetic analogue of
+/// [`convert_standard_lwe_bootstrap_key_to_fourier`](crate::core_crypto::algorithms::convert_standard_lwe_bootstrap_key_to_fourier):
+/// each GGSW polynomial of `input_bsk` is transformed once with the key's [`Ntt64`] engine and
+/// written to the matching slot of `ntt_bsk`. Because the transform is exact, the NTT key is a
+/// lossless representation of the standard key — unlike the Fourier key, which rounds every
+/// coefficient to `f64`.
+///
+/// # Panics
+///
+/// Panics if `input_bsk` and `ntt_bsk` disagree on any dimension.
+pub fn convert_standard_lwe_bootstrap_key_to_ntt<InputCont, OutputCont>(
+    input_bsk: &LweBootstrapKey<InputCont>,
+    ntt_bsk: &mut NttLweBootstrapKey<OutputCont>,
+) where
+    InputCont: Container<Element = u64>,
+    OutputCont: ContainerMut<Element = u64>,
+{
+    assert_eq!(
+        input_bsk.input_lwe_dimension(),
+        ntt_bsk.input_lwe_dimension()
+    );
+    assert_eq!(input_bsk.glwe_size(), ntt_bsk.glwe_size());
+    assert_eq!(input_bsk.polynomial_size(), ntt_bsk.polynomial_size());
+    assert_eq!(
+        input_bsk.decomposition_base_log(),
+        ntt_bsk.decomposition_base_log()
+    );
+    assert_eq!(
+        input_bsk.decomposition_level_count(),
+        ntt_bsk.decomposition_level_count()
+    );
+
+    let ntt = ntt_bsk.ntt().clone();
+    let polynomial_size = ntt_bsk.polynomial_size().0;
+
+    for (standard_poly, ntt_poly) in input_bsk
+        .as_ref()
+        .chunks_exact(polynomial_size)
+        .zip(ntt_bsk.as_mut().chunks_exact_mut(polynomial_size))
+    {
+        // Lift the signed torus coefficients into the prime field before transforming. A standard
+        // key coefficient is a two's-complement `u64`, so `u64::MAX` represents `-1`; reducing it
+        // as an unsigned value would map it to `-1 mod 2^64` instead of `-1 mod p`. Interpret it as
+        // signed and take the Euclidean remainder so negatives land on their positive residue.
+        let modulus = ntt.modulus() as i128;
+        for (dst, &src) in ntt_poly.iter_mut().zip(standard_poly.iter()) {
+            *dst = (src as i64 as i128).rem_euclid(modulus) as u64;
+        }
+        ntt.forward(ntt_poly);
+    }
+}
@@ -0,0 +1,235 @@
+//! Module with the NTT-domain programmable bootstrapping.
+
+use crate::core_crypto::commons::math::ntt::{BarrettReducer, Ntt64};
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+
+/// The arithmetic backend used to run a programmable bootstrap.
+///
+/// This is the integer/float counterpart of the `multithread` switch exposed by the example: it
+/// lets a caller benchmark the `f64` FFT blind rotation against the exact NTT one for the same
+/// parameters, comparing both latency and output error.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BootstrapDomain {
+    /// Run the external products through the `f64` Fourier transform.
+    Fourier,
+    /// Run the external products through the integer NTT.
+    Ntt,
+}
+
+/// Blind-rotate `accumulator` by `input` using an NTT-domain bootstrap key.
+///
+/// The accumulator is kept in the coefficient domain over the prime field; every external product
+/// of the CMux chain is computed by forward-transforming the gadget-decomposed GLWE, multiplying it
+/// coefficient-wise with the pre-transformed key rows and inverse-transforming the sum — so only
+/// modular integer arithmetic is used, with no `f64` FFT.
+pub fn ntt_blind_rotate_assign<InputCont, OutputCont, KeyCont>(
+    input: &LweCiphertext<InputCont>,
+    accumulator: &mut GlweCiphertext<OutputCont>,
+    ntt_bsk: &NttLweBootstrapKey<KeyCont>,
+) where
+    InputCont: Container<Element = u64>,
+    OutputCont: ContainerMut<Element = u64>,
+    KeyCont: Container<Element = u64>,
+{
+    let ntt = ntt_bsk.ntt();
+    let reducer = *ntt.reducer();
+    let glwe_size = ntt_bsk.glwe_size().0;
+    let poly_size = ntt_bsk.polynomial_size().0;
+    let two_n = 2 * poly_size;
+
+    // Lift the (trivially encrypted) torus accumulator into the prime field.
+    let mut acc: Vec<u64> = accumulator
+        .as_ref()
+        .iter()
+        .map(|&c| lift_into_field(c, &reducer))
+        .collect();
+
+    // Initial rotation by the body: multiply the accumulator by X^{-b_hat}.
+    let input = input.as_ref();
+    let body = *input.last().unwrap();
+    let b_hat = rescale_to_degree(body, two_n);
+    rotate_glwe_assign(&mut acc, glwe_size, poly_size, (two_n - b_hat) % two_n, &reducer);
+
+    // CMux chain: one external product per input mask coefficient.
+    let mask = &input[..input.len() - 1];
+    let ggsw_len = ntt_bsk.decomposition_level_count().0 * glwe_size * glwe_size * poly_size;
+    for (mask_element, ggsw) in mask.iter().zip(ntt_bsk.as_ref().chunks_exact(ggsw_len)) {
+        let a_hat = rescale_to_degree(*mask_element, two_n);
+        if a_hat == 0 {
+            continue;
+        }
+
+        // diff = (X^{a_hat} - 1) * ACC; external-product it with the GGSW and add back.
+        let mut diff = acc.clone();
+        rotate_glwe_assign(&mut diff, glwe_size, poly_size, a_hat, &reducer);
+        for (d, a) in diff.iter_mut().zip(acc.iter()) {
+            *d = reducer.sub(*d, *a);
+        }
+
+        let product = ntt_external_product(
+            &diff,
+            ggsw,
+            ntt,
+            glwe_size,
+            poly_size,
+            ntt_bsk.decomposition_base_log(),
+            ntt_bsk.decomposition_level_count(),
+        );
+        for (a, p) in acc.iter_mut().zip(product.iter()) {
+            *a = reducer.add(*a, *p);
+        }
+    }
+
+    // Write the rotated accumulator back into the torus representation.
+    for (dst, &src) in accumulator.as_mut().iter_mut().zip(acc.iter()) {
+        *dst = lower_from_field(src, &reducer);
+    }
+}
+
+/// Run a programmable bootstrap of `input` into `output` entirely in integer arithmetic.
+///
+/// Mirrors
+/// [`programmable_bootstrap_lwe_ciphertext`](crate::core_crypto::algorithms::programmable_bootstrap_lwe_ciphertext)
+/// but takes an [`NttLweBootstrapKey`]. The `accumulator` encodes the look-up table exactly as for
+/// the Fourier path.
+pub fn ntt_programmable_bootstrap_lwe_ciphertext<InputCont, OutputCont, AccCont, KeyCont>(
+    input: &LweCiphertext<InputCont>,
+    output: &mut LweCiphertext<OutputCont>,
+    accumulator: &GlweCiphertext<AccCont>,
+    ntt_bsk: &NttLweBootstrapKey<KeyCont>,
+) where
+    InputCont: Container<Element = u64>,
+    OutputCont: ContainerMut<Element = u64>,
+    AccCont: Container<Element = u64>,
+    KeyCont: Container<Element = u64>,
+{
+    let mut rotated = accumulator.clone_into_owned();
+    ntt_blind_rotate_assign(input, &mut rotated, ntt_bsk);
+    extract_lwe_sample_from_glwe_ciphertext(&rotated, output, MonomialDegree(0));
+}
+
+/// Compute one GGSW external product in the NTT domain.
+///
+/// `glwe` is the GLWE operand in the field coefficient domain; `ggsw` are the pre-transformed key
+/// rows (`level_count * glwe_size` GLWE ciphertexts, each of `glwe_size` polynomials). Each of the
+/// `glwe_size` operand polynomials is gadget-decomposed into `level_count` digits, each digit is
+/// forward-transformed and multiplied coefficient-wise with the matching key row, and the per-output
+/// sums are inverse-transformed back to the coefficient domain.
+fn ntt_external_product(
+    glwe: &[u64],
+    ggsw: &[u64],
+    ntt: &Ntt64,
+    glwe_size: usize,
+    poly_size: usize,
+    base_log: DecompositionBaseLog,
+    level_count: DecompositionLevelCount,
+) -> Vec<u64> {
+    let reducer = *ntt.reducer();
+    // NTT-domain accumulators, one per output polynomial of the resulting GLWE.
+    let mut ntt_acc = vec![vec![0u64; poly_size]; glwe_size];
+
+    for in_poly in 0..glwe_size {
+        let coeffs = &glwe[in_poly * poly_size..(in_poly + 1) * poly_size];
+        for level in 0..level_count.0 {
+            // Gadget digit of this level, transformed once and reused across every output.
+            let mut digit = decompose_level(coeffs, base_log, level, &reducer);
+            ntt.forward(&mut digit);
+
+            let row = in_poly * level_count.0 + level;
+            for out_poly in 0..glwe_size {
+                let key_poly = &ggsw[(row * glwe_size + out_poly) * poly_size
+                    ..(row * glwe_size + out_poly + 1) * poly_size];
+                let acc = &mut ntt_acc[out_poly];
+                for ((a, &d), &k) in acc.iter_mut().zip(digit.iter()).zip(key_poly.iter()) {
+                    *a = reducer.add(*a, reducer.mul(d, k));
+                }
+            }
+        }
+    }
+
+    let mut result = vec![0u64; glwe_size * poly_size];
+    for (out_poly, mut ntt_poly) in ntt_acc.into_iter().enumerate() {
+        ntt.inverse(&mut ntt_poly);
+        result[out_poly * poly_size..(out_poly + 1) * poly_size].copy_from_slice(&ntt_poly);
+    }
+    result
+}
+
+/// Extract the `level`-th gadget digit (base `2^base_log`) of every coefficient as a centered
+/// field element, mirroring the [`SignedDecomposer`](crate::core_crypto::commons::math::decomposition::SignedDecomposer)
+/// used on the Fourier path.
+pub(crate) fn decompose_level(
+    coeffs: &[u64],
+    base_log: DecompositionBaseLog,
+    level: usize,
+    reducer: &BarrettReducer,
+) -> Vec<u64> {
+    let base = 1u64 << base_log.0;
+    let shift = base_log.0 * (level + 1);
+    coeffs
+        .iter()
+        .map(|&c| {
+            // Round-to-nearest digit in (-base/2, base/2], reduced back into the field.
+            let rounded = (c >> (shift - 1)).wrapping_add(1) >> 1;
+            let digit = rounded & (base - 1);
+            let signed = if digit >= base / 2 {
+                digit as i128 - base as i128
+            } else {
+                digit as i128
+            };
+            signed.rem_euclid(reducer.modulus() as i128) as u64
+        })
+        .collect()
+}
+
+/// Negacyclic monomial multiplication of every polynomial of a GLWE ciphertext by `X^degree`, in
+/// place, over the prime field.
+pub(crate) fn rotate_glwe_assign(
+    glwe: &mut [u64],
+    glwe_size: usize,
+    poly_size: usize,
+    degree: usize,
+    reducer: &BarrettReducer,
+) {
+    let degree = degree % (2 * poly_size);
+    let mut scratch = vec![0u64; poly_size];
+    for poly in glwe.chunks_exact_mut(poly_size) {
+        scratch.iter_mut().for_each(|c| *c = 0);
+        for (i, &value) in poly.iter().enumerate() {
+            let target = i + degree;
+            let pos = target % poly_size;
+            if (target / poly_size) % 2 == 1 {
+                scratch[pos] = reducer.sub(scratch[pos], value);
+            } else {
+                scratch[pos] = reducer.add(scratch[pos], value);
+            }
+        }
+        poly.copy_from_slice(&scratch);
+    }
+    debug_assert_eq!(glwe.len(), glwe_size * poly_size);
+}
+
+/// Rescale a torus value to a rotation degree in `0..modulus_degree`, rounding to nearest.
+#[inline]
+pub(crate) fn rescale_to_degree(value: u64, modulus_degree: usize) -> usize {
+    (((value as u128 * modulus_degree as u128) + (1u128 << 63)) >> 64) as usize % modulus_degree
+}
+
+/// Lift a two's-complement torus coefficient into the prime field (signed reduction).
+#[inline]
+pub(crate) fn lift_into_field(value: u64, reducer: &BarrettReducer) -> u64 {
+    (value as i64 as i128).rem_euclid(reducer.modulus() as i128) as u64
+}
+
+/// Lower a field element back to its centered two's-complement torus representation.
+#[inline]
+pub(crate) fn lower_from_field(value: u64, reducer: &BarrettReducer) -> u64 {
+    let modulus = reducer.modulus();
+    if value > modulus / 2 {
+        value.wrapping_sub(modulus)
+    } else {
+        value
+    }
+}
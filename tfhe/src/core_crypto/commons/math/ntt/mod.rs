@@ -0,0 +1,254 @@
+//! Module providing a negacyclic number-theoretic transform (NTT) over a prime modulus.
+//!
+//! The NTT plays for integer arithmetic the role the FFT plays for the `f64` path: it turns the
+//! negacyclic polynomial products of the external product into coefficient-wise multiplications.
+//! Working modulo a prime keeps the whole blind rotation in exact integer arithmetic, removing the
+//! floating-point error the Fourier path incurs and lifting the precision limit it imposes on the
+//! torus modulus.
+
+/// Barrett reduction constants for a fixed prime modulus.
+///
+/// Per-coefficient hardware division dominates the cost of the NTT butterflies, so we reduce modulo
+/// `p` with a single multiply-shift instead. We precompute `m = floor(2^k / p)` once per modulus
+/// (with `k = 2 * BITS`), then estimate `x / p` from the high bits of `x * m` and fix up the
+/// remainder with at most one conditional subtraction.
+#[derive(Clone, Copy, Debug)]
+pub struct BarrettReducer {
+    modulus: u64,
+    /// `floor(2^128 / modulus)`, the Barrett ratio. For an odd prime `< 2^62` it is `> 2^66`; since
+    /// `modulus > 2` it always fits a `u128`.
+    ratio: u128,
+}
+
+impl BarrettReducer {
+    /// Precompute the Barrett constant for `modulus`.
+    #[inline]
+    pub fn new(modulus: u64) -> Self {
+        debug_assert!(modulus > 2, "the NTT prime must be an odd prime");
+        debug_assert!(
+            modulus < (1u64 << 62),
+            "the NTT prime must be smaller than 2^62 so products stay below 2^124"
+        );
+        // floor(2^128 / modulus); for an odd modulus this equals u128::MAX / modulus.
+        let ratio = u128::MAX / modulus as u128;
+        Self { modulus, ratio }
+    }
+
+    /// The reduced modulus.
+    #[inline]
+    pub fn modulus(&self) -> u64 {
+        self.modulus
+    }
+
+    /// Reduce `x` modulo the precomputed prime using a multiply-shift and at most one conditional
+    /// subtraction.
+    ///
+    /// `x` must be a modular product, i.e. `x < modulus^2 < 2^124`. The quotient is estimated as
+    /// the top `128` bits of the `256`-bit product `x * ratio`, computed without overflow via
+    /// [`mul_hi_128`]; the estimate undershoots by at most one modulus.
+    #[inline]
+    pub fn reduce(&self, x: u128) -> u64 {
+        let quotient = mul_hi_128(x, self.ratio);
+        let mut reduced = x.wrapping_sub(quotient.wrapping_mul(self.modulus as u128)) as u64;
+        if reduced >= self.modulus {
+            reduced -= self.modulus;
+        }
+        reduced
+    }
+
+    /// Reduce a modular product `a * b`.
+    #[inline]
+    pub fn mul(&self, a: u64, b: u64) -> u64 {
+        self.reduce(a as u128 * b as u128)
+    }
+
+    /// Modular addition without a full reduction.
+    #[inline]
+    pub fn add(&self, a: u64, b: u64) -> u64 {
+        let sum = a + b;
+        if sum >= self.modulus {
+            sum - self.modulus
+        } else {
+            sum
+        }
+    }
+
+    /// Modular subtraction.
+    #[inline]
+    pub fn sub(&self, a: u64, b: u64) -> u64 {
+        if a >= b {
+            a - b
+        } else {
+            a + self.modulus - b
+        }
+    }
+}
+
+/// A negacyclic NTT engine for a polynomial ring of a given size over a fixed prime.
+///
+/// The engine caches the twiddle factors and the Barrett reducer so a transform of a polynomial of
+/// the configured size costs only butterflies. It is cheap to clone and safe to share across the
+/// bootstrap key rows.
+#[derive(Clone, Debug)]
+pub struct Ntt64 {
+    reducer: BarrettReducer,
+    /// Forward twiddles in bit-reversed order.
+    twiddles: Vec<u64>,
+    /// Inverse twiddles in bit-reversed order.
+    inv_twiddles: Vec<u64>,
+    /// Modular inverse of the transform size, applied on the way back.
+    size_inverse: u64,
+    size: usize,
+}
+
+impl Ntt64 {
+    /// Build an engine for polynomials of length `size` over `modulus`.
+    ///
+    /// `modulus` must be a prime congruent to `1 mod 2 * size` so that a primitive `2 * size`-th
+    /// root of unity exists and the transform is negacyclic.
+    pub fn new(size: usize, modulus: u64, primitive_root: u64) -> Self {
+        debug_assert!(size.is_power_of_two());
+        let reducer = BarrettReducer::new(modulus);
+
+        // psi is a primitive 2*size-th root of unity; omega = psi^2 is the size-th root.
+        let psi = primitive_root;
+        let psi_inv = mod_inverse(psi, modulus);
+
+        let mut twiddles = vec![0u64; size];
+        let mut inv_twiddles = vec![0u64; size];
+        let mut power = 1u64;
+        let mut inv_power = 1u64;
+        for k in 0..size {
+            let rev = bit_reverse(k, size.trailing_zeros());
+            twiddles[rev] = power;
+            inv_twiddles[rev] = inv_power;
+            power = reducer.mul(power, psi);
+            inv_power = reducer.mul(inv_power, psi_inv);
+        }
+
+        let size_inverse = mod_inverse(size as u64, modulus);
+
+        Self {
+            reducer,
+            twiddles,
+            inv_twiddles,
+            size_inverse,
+            size,
+        }
+    }
+
+    /// The polynomial size this engine transforms.
+    #[inline]
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// The prime modulus backing the transform.
+    #[inline]
+    pub fn modulus(&self) -> u64 {
+        self.reducer.modulus()
+    }
+
+    /// The Barrett reducer backing the transform, for callers that need modular arithmetic in the
+    /// same field (e.g. the external-product chain of the blind rotation).
+    #[inline]
+    pub fn reducer(&self) -> &BarrettReducer {
+        &self.reducer
+    }
+
+    /// Forward negacyclic transform, in place, using a decimation-in-time Cooley-Tukey schedule.
+    pub fn forward(&self, data: &mut [u64]) {
+        debug_assert_eq!(data.len(), self.size);
+        let reducer = &self.reducer;
+        let mut len = self.size / 2;
+        let mut twiddle_step = 1;
+        while len >= 1 {
+            let mut offset = 0;
+            let mut t = twiddle_step;
+            while offset < self.size {
+                let w = self.twiddles[t];
+                for i in offset..offset + len {
+                    let u = data[i];
+                    let v = reducer.mul(data[i + len], w);
+                    data[i] = reducer.add(u, v);
+                    data[i + len] = reducer.sub(u, v);
+                }
+                offset += len << 1;
+                t += 1;
+            }
+            twiddle_step <<= 1;
+            len >>= 1;
+        }
+    }
+
+    /// Inverse negacyclic transform, in place.
+    pub fn inverse(&self, data: &mut [u64]) {
+        debug_assert_eq!(data.len(), self.size);
+        let reducer = &self.reducer;
+        let mut len = 1;
+        let mut twiddle_step = self.size / 2;
+        while len < self.size {
+            let mut offset = 0;
+            let mut t = twiddle_step;
+            while offset < self.size {
+                let w = self.inv_twiddles[t];
+                for i in offset..offset + len {
+                    let u = data[i];
+                    let v = data[i + len];
+                    data[i] = reducer.add(u, v);
+                    data[i + len] = reducer.mul(reducer.sub(u, v), w);
+                }
+                offset += len << 1;
+                t += 1;
+            }
+            twiddle_step >>= 1;
+            len <<= 1;
+        }
+        for a in data.iter_mut() {
+            *a = reducer.mul(*a, self.size_inverse);
+        }
+    }
+}
+
+/// Modular inverse of `a` modulo the prime `modulus`, by Fermat's little theorem.
+fn mod_inverse(a: u64, modulus: u64) -> u64 {
+    // Fermat's little theorem: a^(p-2) mod p.
+    let reducer = BarrettReducer::new(modulus);
+    let mut result = 1u64;
+    let mut base = a % modulus;
+    let mut exp = modulus - 2;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = reducer.mul(result, base);
+        }
+        base = reducer.mul(base, base);
+        exp >>= 1;
+    }
+    result
+}
+
+/// Return the high `128` bits of the `256`-bit product `a * b`, computed from `64`-bit limbs so no
+/// intermediate overflows a `u128`.
+#[inline]
+fn mul_hi_128(a: u128, b: u128) -> u128 {
+    const MASK: u128 = u64::MAX as u128;
+    let (a_lo, a_hi) = (a & MASK, a >> 64);
+    let (b_lo, b_hi) = (b & MASK, b >> 64);
+
+    let lo_lo = a_lo * b_lo;
+    let lo_hi = a_lo * b_hi;
+    let hi_lo = a_hi * b_lo;
+    let hi_hi = a_hi * b_hi;
+
+    let carry = (lo_lo >> 64) + (lo_hi & MASK) + (hi_lo & MASK);
+    hi_hi + (lo_hi >> 64) + (hi_lo >> 64) + (carry >> 64)
+}
+
+/// Reverse the low `bits` bits of `index`.
+fn bit_reverse(index: usize, bits: u32) -> usize {
+    let mut reversed = 0;
+    for i in 0..bits {
+        reversed |= ((index >> i) & 1) << (bits - 1 - i);
+    }
+    reversed
+}
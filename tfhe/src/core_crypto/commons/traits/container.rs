@@ -69,6 +69,12 @@ pub trait Split: Sized {
 
     fn into_chunks(self, chunk_size: usize) -> Self::Chunks;
     fn split_into(self, chunk_count: usize) -> Self::Chunks;
+    /// Split into `len / min_chunk_len` chunks (at least one), so each chunk is at least
+    /// `min_chunk_len` long when the input is. Unlike [`split_into`](Self::split_into), which cuts
+    /// into a fixed number of pieces regardless of work size, this clamps the chunk count so small
+    /// inputs are not cut into uselessly tiny tasks. As with `split_into`, the length must be a
+    /// multiple of that chunk count.
+    fn split_with_min_len(self, min_chunk_len: usize) -> Self::Chunks;
     fn split_at(self, mid: usize) -> (Self, Self);
 }
 
@@ -91,6 +97,11 @@ impl<'a, T> Split for &'a [T] {
         }
     }
     #[inline]
+    fn split_with_min_len(self, min_chunk_len: usize) -> Self::Chunks {
+        let chunk_count = (self.len() / min_chunk_len.max(1)).max(1);
+        self.split_into(chunk_count)
+    }
+    #[inline]
     fn split_at(self, mid: usize) -> (Self, Self) {
         self.split_at(mid)
     }
@@ -115,6 +126,11 @@ impl<'a, T> Split for &'a mut [T] {
         }
     }
     #[inline]
+    fn split_with_min_len(self, min_chunk_len: usize) -> Self::Chunks {
+        let chunk_count = (self.len() / min_chunk_len.max(1)).max(1);
+        self.split_into(chunk_count)
+    }
+    #[inline]
     fn split_at(self, mid: usize) -> (Self, Self) {
         self.split_at_mut(mid)
     }
@@ -125,6 +141,12 @@ pub trait ParSplit: Sized {
 
     fn into_par_chunks(self, chunk_size: usize) -> Self::Chunks;
     fn par_split_into(self, chunk_count: usize) -> Self::Chunks;
+    /// Split into rayon chunks of `len / max(len / min_chunk_len, 1)` elements. Unlike
+    /// [`par_split_into`](Self::par_split_into), which always cuts into a fixed number of pieces,
+    /// this clamps the chunk count so small polynomials do not oversubscribe the thread pool: every
+    /// chunk but the last is at least `min_chunk_len` long, and the last one, which holds any
+    /// remainder, can be shorter.
+    fn par_split_with_min_len(self, min_chunk_len: usize) -> Self::Chunks;
     fn par_split_at(self, mid: usize) -> (Self, Self);
 }
 
@@ -144,6 +166,11 @@ impl<'a, T: Sync> ParSplit for &'a [T] {
         }
     }
     #[inline]
+    fn par_split_with_min_len(self, min_chunk_len: usize) -> Self::Chunks {
+        let chunk_count = (self.len() / min_chunk_len.max(1)).max(1);
+        self.par_split_into(chunk_count)
+    }
+    #[inline]
     fn par_split_at(self, mid: usize) -> (Self, Self) {
         self.split_at(mid)
     }
@@ -165,6 +192,11 @@ impl<'a, T: Send> ParSplit for &'a mut [T] {
         }
     }
     #[inline]
+    fn par_split_with_min_len(self, min_chunk_len: usize) -> Self::Chunks {
+        let chunk_count = (self.len() / min_chunk_len.max(1)).max(1);
+        self.par_split_into(chunk_count)
+    }
+    #[inline]
     fn par_split_at(self, mid: usize) -> (Self, Self) {
         self.split_at_mut(mid)
     }
@@ -0,0 +1,115 @@
+//! Module containing the definition of the [`LwePrivateFunctionalPackingKeyswitchKey`].
+
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+
+/// A private functional packing keyswitch key.
+///
+/// Where an ordinary keyswitch key moves a single LWE ciphertext from one key to another, this key
+/// packs up to `polynomial_size` input LWE ciphertexts into the coefficient slots of one GLWE
+/// ciphertext, optionally applying a per-slot linear function baked into the key at generation
+/// time. Its layout follows the box/accumulator convention used throughout the example: one gadget
+/// matrix per input key element (mask coefficients plus the body), each row being a GLWE encryption
+/// of a decomposition level of the corresponding extended secret-key coefficient (`-s_i` for a mask
+/// element, `1` for the body) times the private function.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LwePrivateFunctionalPackingKeyswitchKey<C: Container> {
+    data: C,
+    decomposition_base_log: DecompositionBaseLog,
+    decomposition_level_count: DecompositionLevelCount,
+    output_glwe_size: GlweSize,
+    output_polynomial_size: PolynomialSize,
+}
+
+impl<C: Container> LwePrivateFunctionalPackingKeyswitchKey<C> {
+    /// Wrap an existing container as a private functional packing keyswitch key.
+    pub fn from_container(
+        container: C,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        output_glwe_size: GlweSize,
+        output_polynomial_size: PolynomialSize,
+    ) -> Self {
+        assert!(
+            container.container_len()
+                % (decomposition_level_count.0 * output_glwe_size.0 * output_polynomial_size.0)
+                == 0,
+            "The provided container length is not consistent with the key dimensions"
+        );
+        Self {
+            data: container,
+            decomposition_base_log,
+            decomposition_level_count,
+            output_glwe_size,
+            output_polynomial_size,
+        }
+    }
+
+    /// Return the [`DecompositionBaseLog`] of the key.
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+
+    /// Return the [`DecompositionLevelCount`] of the key.
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+
+    /// Return the output [`GlweSize`] of the key.
+    pub fn output_glwe_size(&self) -> GlweSize {
+        self.output_glwe_size
+    }
+
+    /// Return the output [`PolynomialSize`] of the key.
+    pub fn output_polynomial_size(&self) -> PolynomialSize {
+        self.output_polynomial_size
+    }
+
+    /// Return the number of input LWE key elements (including the body) this key keyswitches from.
+    pub fn input_key_element_count(&self) -> usize {
+        self.data.container_len()
+            / (self.decomposition_level_count.0
+                * self.output_glwe_size.0
+                * self.output_polynomial_size.0)
+    }
+
+    /// Return an immutable view over the underlying container.
+    pub fn as_ref(&self) -> &[C::Element] {
+        self.data.as_ref()
+    }
+}
+
+impl<C: ContainerMut> LwePrivateFunctionalPackingKeyswitchKey<C> {
+    /// Return a mutable view over the underlying container.
+    pub fn as_mut(&mut self) -> &mut [C::Element] {
+        self.data.as_mut()
+    }
+}
+
+/// An [`LwePrivateFunctionalPackingKeyswitchKey`] owning its coefficients.
+pub type LwePrivateFunctionalPackingKeyswitchKeyOwned<Scalar> =
+    LwePrivateFunctionalPackingKeyswitchKey<Vec<Scalar>>;
+
+impl<Scalar: Copy + Default> LwePrivateFunctionalPackingKeyswitchKeyOwned<Scalar> {
+    /// Allocate a new zeroed key ready to be filled by the generation routine.
+    pub fn new(
+        fill_with: Scalar,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        input_key_lwe_dimension: LweDimension,
+        output_glwe_size: GlweSize,
+        output_polynomial_size: PolynomialSize,
+    ) -> Self {
+        let len = input_key_lwe_dimension.to_lwe_size().0
+            * decomposition_level_count.0
+            * output_glwe_size.0
+            * output_polynomial_size.0;
+        Self::from_container(
+            vec![fill_with; len],
+            decomposition_base_log,
+            decomposition_level_count,
+            output_glwe_size,
+            output_polynomial_size,
+        )
+    }
+}
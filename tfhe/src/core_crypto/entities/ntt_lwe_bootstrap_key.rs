@@ -0,0 +1,133 @@
+//! Module containing the definition of the [`NttLweBootstrapKey`].
+
+use crate::core_crypto::commons::math::ntt::Ntt64;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+
+/// An LWE bootstrap key stored in the NTT domain.
+///
+/// This is the integer-arithmetic counterpart of [`FourierLweBootstrapKey`](super::FourierLweBootstrapKey):
+/// every GGSW polynomial of the standard key is transformed once with a negacyclic
+/// [`Ntt64`] and kept in the NTT domain, so the external products of the blind rotation reduce to
+/// coefficient-wise modular multiplications instead of `f64` FFTs. Use
+/// [`convert_standard_lwe_bootstrap_key_to_ntt`](crate::core_crypto::algorithms::convert_standard_lwe_bootstrap_key_to_ntt)
+/// to fill one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NttLweBootstrapKey<C: Container<Element = u64>> {
+    data: C,
+    input_lwe_dimension: LweDimension,
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    decomposition_base_log: DecompositionBaseLog,
+    decomposition_level_count: DecompositionLevelCount,
+    ntt: Ntt64,
+}
+
+impl<C: Container<Element = u64>> NttLweBootstrapKey<C> {
+    /// Wrap an existing container of NTT-domain coefficients.
+    ///
+    /// The container length must be `input_lwe_dimension * level_count * glwe_size^2 *
+    /// polynomial_size`, mirroring the standard key layout with the polynomials replaced by their
+    /// NTT coefficients.
+    pub fn from_container(
+        container: C,
+        input_lwe_dimension: LweDimension,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        ntt: Ntt64,
+    ) -> Self {
+        assert_eq!(
+            container.container_len(),
+            input_lwe_dimension.0
+                * decomposition_level_count.0
+                * glwe_size.0
+                * glwe_size.0
+                * polynomial_size.0,
+            "The provided container does not match the bootstrap key dimensions"
+        );
+        assert_eq!(ntt.size(), polynomial_size.0);
+        Self {
+            data: container,
+            input_lwe_dimension,
+            glwe_size,
+            polynomial_size,
+            decomposition_base_log,
+            decomposition_level_count,
+            ntt,
+        }
+    }
+
+    /// Return the input [`LweDimension`] of the key.
+    pub fn input_lwe_dimension(&self) -> LweDimension {
+        self.input_lwe_dimension
+    }
+
+    /// Return the [`GlweSize`] of the key.
+    pub fn glwe_size(&self) -> GlweSize {
+        self.glwe_size
+    }
+
+    /// Return the [`PolynomialSize`] of the key.
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    /// Return the [`DecompositionBaseLog`] of the key.
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+
+    /// Return the [`DecompositionLevelCount`] of the key.
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+
+    /// Return the [`Ntt64`] engine used to transform the key.
+    pub fn ntt(&self) -> &Ntt64 {
+        &self.ntt
+    }
+
+    /// Return a view over the NTT-domain coefficients.
+    pub fn as_ref(&self) -> &[u64] {
+        self.data.as_ref()
+    }
+}
+
+impl<C: ContainerMut<Element = u64>> NttLweBootstrapKey<C> {
+    /// Return a mutable view over the NTT-domain coefficients.
+    pub fn as_mut(&mut self) -> &mut [u64] {
+        self.data.as_mut()
+    }
+}
+
+/// An [`NttLweBootstrapKey`] owning its coefficients.
+pub type NttLweBootstrapKeyOwned = NttLweBootstrapKey<Vec<u64>>;
+
+impl NttLweBootstrapKeyOwned {
+    /// Allocate a new zeroed NTT bootstrap key ready to be filled by the conversion routine.
+    pub fn new(
+        input_lwe_dimension: LweDimension,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        ntt: Ntt64,
+    ) -> Self {
+        let len = input_lwe_dimension.0
+            * decomposition_level_count.0
+            * glwe_size.0
+            * glwe_size.0
+            * polynomial_size.0;
+        Self::from_container(
+            vec![0u64; len],
+            input_lwe_dimension,
+            glwe_size,
+            polynomial_size,
+            decomposition_base_log,
+            decomposition_level_count,
+            ntt,
+        )
+    }
+}